@@ -0,0 +1,179 @@
+use crate::{
+    core::Time,
+    legion::prelude::{Resources, Schedule, Universe, World},
+    render::renderer::Renderer,
+};
+
+/// How often a stage's schedule should be executed as the app steps forward.
+pub enum RunCriteria {
+    /// Executes the stage's schedule once per app update, but only while `should_run` returns
+    /// `true`. Useful for stages that should be skipped entirely under some condition, e.g. while
+    /// a game is paused.
+    Predicate(Box<dyn FnMut(&mut Resources) -> bool + Send + Sync>),
+    /// Executes the stage's schedule at a fixed rate, accumulating the `Time` resource's delta
+    /// and catching up (running zero, one, or several times) on each app update.
+    FixedTimestep {
+        step_seconds: f32,
+        accumulated_seconds: f32,
+    },
+}
+
+/// One named, independently scheduled slice of an [`App`]'s update, produced from an
+/// [`AppBuilder`](crate::app::AppBuilder)'s `stage_order` by
+/// [`AppBuilder::build`](crate::app::AppBuilder::build).
+pub struct AppStage {
+    pub name: String,
+    pub schedule: Schedule,
+    pub criteria: Option<RunCriteria>,
+}
+
+/// A fully built Bevy app, ready to run its stages against its `World` and `Resources`.
+pub struct App {
+    pub universe: Universe,
+    pub world: World,
+    pub stages: Vec<AppStage>,
+    pub resources: Resources,
+    pub renderer: Option<Box<dyn Renderer>>,
+}
+
+impl App {
+    pub fn new(
+        universe: Universe,
+        world: World,
+        stages: Vec<AppStage>,
+        resources: Resources,
+        renderer: Option<Box<dyn Renderer>>,
+    ) -> Self {
+        App {
+            universe,
+            world,
+            stages,
+            resources,
+            renderer,
+        }
+    }
+
+    /// Runs the app's update loop until the process exits. With a [`Renderer`] configured, each
+    /// iteration steps the ECS schedules and then hands control to the renderer so it can pump
+    /// its window's events and draw the frame. With no renderer configured (see
+    /// [`AppBuilder::add_headless_defaults`](crate::app::AppBuilder::add_headless_defaults)),
+    /// the loop instead paces itself against the wall clock, so headless apps don't need a
+    /// window or a GPU at all.
+    pub fn run(&mut self) {
+        if self.renderer.is_none() {
+            self.run_headless();
+            return;
+        }
+
+        loop {
+            self.update();
+            self.render();
+        }
+    }
+
+    /// Hands the frame to the configured renderer, which pumps its window's event loop and
+    /// draws using the render graph nodes that were set up via
+    /// [`AppBuilder::add_render_graph_defaults`](crate::app::AppBuilder::add_render_graph_defaults).
+    /// A no-op when `renderer` is `None`.
+    fn render(&mut self) {
+        if let Some(renderer) = self.renderer.as_mut() {
+            renderer.update(&mut self.world, &mut self.resources);
+        }
+    }
+
+    fn run_headless(&mut self) {
+        let step = std::time::Duration::from_secs_f32(1.0 / 60.0);
+        loop {
+            let frame_start = std::time::Instant::now();
+            self.update();
+
+            let elapsed = frame_start.elapsed();
+            if elapsed < step {
+                std::thread::sleep(step - elapsed);
+            }
+        }
+    }
+
+    /// Advances every stage by one app update: stages without run criteria execute
+    /// unconditionally, `Predicate` stages execute when their predicate returns `true`, and
+    /// `FixedTimestep` stages execute as many times as needed to consume the accumulated delta.
+    pub fn update(&mut self) {
+        let delta_seconds = {
+            let mut time = self.resources.get_mut::<Time>().unwrap();
+            time.update();
+            time.delta_seconds
+        };
+
+        for stage in self.stages.iter_mut() {
+            match stage.criteria.as_mut() {
+                None => stage.schedule.execute(&mut self.world, &mut self.resources),
+                Some(RunCriteria::Predicate(should_run)) => {
+                    if should_run(&mut self.resources) {
+                        stage.schedule.execute(&mut self.world, &mut self.resources);
+                    }
+                }
+                Some(RunCriteria::FixedTimestep {
+                    step_seconds,
+                    accumulated_seconds,
+                }) => {
+                    let runs =
+                        fixed_timestep_run_count(*step_seconds, accumulated_seconds, delta_seconds);
+                    for _ in 0..runs {
+                        stage.schedule.execute(&mut self.world, &mut self.resources);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Given a fixed timestep's `step_seconds` and this update's `delta_seconds`, advances
+/// `accumulated_seconds` by `delta_seconds` and returns how many whole steps it can now pay for
+/// (consuming that much of the accumulator). `step_seconds` is assumed positive; that's enforced
+/// once, up front, by
+/// [`AppBuilder::add_stage_with_criteria`](crate::app::AppBuilder::add_stage_with_criteria)
+/// rather than on every call here.
+fn fixed_timestep_run_count(
+    step_seconds: f32,
+    accumulated_seconds: &mut f32,
+    delta_seconds: f32,
+) -> u32 {
+    *accumulated_seconds += delta_seconds;
+
+    let mut runs = 0;
+    while *accumulated_seconds >= step_seconds {
+        *accumulated_seconds -= step_seconds;
+        runs += 1;
+    }
+
+    runs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fixed_timestep_does_not_run_before_a_full_step_has_accumulated() {
+        let mut accumulated = 0.0;
+        let runs = fixed_timestep_run_count(1.0 / 60.0, &mut accumulated, 1.0 / 120.0);
+        assert_eq!(runs, 0);
+        assert!(accumulated > 0.0);
+    }
+
+    #[test]
+    fn fixed_timestep_runs_once_for_exactly_one_step() {
+        let mut accumulated = 0.0;
+        let runs = fixed_timestep_run_count(1.0 / 60.0, &mut accumulated, 1.0 / 60.0);
+        assert_eq!(runs, 1);
+        assert!(accumulated.abs() < 1e-6);
+    }
+
+    #[test]
+    fn fixed_timestep_catches_up_multiple_steps_in_one_update() {
+        let mut accumulated = 0.0;
+        let runs = fixed_timestep_run_count(1.0 / 60.0, &mut accumulated, 3.5 / 60.0);
+        assert_eq!(runs, 3);
+        assert!((accumulated - 0.5 / 60.0).abs() < 1e-6);
+    }
+}
@@ -1,9 +1,9 @@
 use crate::{
-    app::{system_stage, App},
+    app::{system_stage, App, AppStage, RunCriteria},
     asset::*,
     core::Time,
     legion::prelude::{Resources, Runnable, Schedulable, Schedule, Universe, World},
-    plugin::load_plugin,
+    plugin::{load_plugin, Plugin},
     prelude::StandardMaterial,
     render::{
         draw_target::draw_targets::*, mesh::Mesh, pass::passes::*, pipeline::pipelines::*,
@@ -31,6 +31,7 @@ pub struct AppBuilder {
     pub setup_systems: Vec<Box<dyn Schedulable>>,
     pub system_stages: HashMap<String, Vec<Box<dyn Schedulable>>>,
     pub runnable_stages: HashMap<String, Vec<Box<dyn Runnable>>>,
+    pub stage_criteria: HashMap<String, RunCriteria>,
     pub stage_order: Vec<String>,
 }
 
@@ -48,6 +49,7 @@ impl AppBuilder {
             setup_systems: Vec::new(),
             system_stages: HashMap::new(),
             runnable_stages: HashMap::new(),
+            stage_criteria: HashMap::new(),
             stage_order: Vec::new(),
         }
     }
@@ -64,8 +66,10 @@ impl AppBuilder {
             self.resources.as_mut().unwrap(),
         );
 
-        let mut schedule_builder = Schedule::builder();
+        let mut stages = Vec::new();
         for stage_name in self.stage_order.iter() {
+            let mut schedule_builder = Schedule::builder();
+
             if let Some((_name, stage_systems)) = self.system_stages.remove_entry(stage_name) {
                 for system in stage_systems {
                     schedule_builder = schedule_builder.add_system(system);
@@ -81,17 +85,40 @@ impl AppBuilder {
 
                 schedule_builder = schedule_builder.flush();
             }
+
+            stages.push(AppStage {
+                name: stage_name.clone(),
+                schedule: schedule_builder.build(),
+                criteria: self.stage_criteria.remove(stage_name),
+            });
+        }
+
+        if let Some(missing_stage) = self
+            .system_stages
+            .keys()
+            .chain(self.runnable_stages.keys())
+            .next()
+        {
+            panic!(
+                "Stage does not exist in stage_order: {}. Systems and runnables can only be \
+                 added to stages that have been registered via `add_stage`, `add_stage_before`, \
+                 `add_stage_after`, `add_system_to_stage`, or `add_runnable_to_stage`.",
+                missing_stage
+            );
         }
 
-        self.resources
-            .as_mut()
-            .unwrap()
-            .insert(self.render_graph.take().unwrap());
+        // Headless apps (see `add_headless_defaults`) never configure a renderer, so there's
+        // nothing that would consume a `RenderGraph` resource; skip inserting one rather than
+        // pretending a render graph exists when there's no renderer to run it.
+        let render_graph = self.render_graph.take().unwrap();
+        if self.renderer.is_some() {
+            self.resources.as_mut().unwrap().insert(render_graph);
+        }
 
         App::new(
             self.universe.take().unwrap(),
             self.world.take().unwrap(),
-            schedule_builder.build(),
+            stages,
             self.resources.take().unwrap(),
             self.renderer.take(),
         )
@@ -131,6 +158,9 @@ impl AppBuilder {
         if let None = self.system_stages.get(stage_name) {
             self.system_stages
                 .insert(stage_name.to_string(), Vec::new());
+        }
+
+        if !self.stage_order.iter().any(|stage| stage == stage_name) {
             self.stage_order.push(stage_name.to_string());
         }
 
@@ -140,6 +170,59 @@ impl AppBuilder {
         self
     }
 
+    /// Adds an empty stage named `stage_name` to the end of the stage order. This is useful for
+    /// registering a stage up front so that later calls to [`AppBuilder::add_stage_before`] or
+    /// [`AppBuilder::add_stage_after`] have something to anchor to.
+    pub fn add_stage(&mut self, stage_name: &str) -> &mut Self {
+        if !self.stage_order.iter().any(|stage| stage == stage_name) {
+            self.stage_order.push(stage_name.to_string());
+        }
+        self
+    }
+
+    /// Adds a new stage named `stage_name` immediately before `target` in the stage order.
+    /// Panics if `target` has not been added yet.
+    pub fn add_stage_before(&mut self, target: &str, stage_name: &str) -> &mut Self {
+        insert_stage_relative_to(&mut self.stage_order, target, stage_name, 0);
+        self
+    }
+
+    /// Adds a new stage named `stage_name` immediately after `target` in the stage order.
+    /// Panics if `target` has not been added yet.
+    pub fn add_stage_after(&mut self, target: &str, stage_name: &str) -> &mut Self {
+        insert_stage_relative_to(&mut self.stage_order, target, stage_name, 1);
+        self
+    }
+
+    /// Adds `stage_name` to the end of the stage order (if it isn't already present) and
+    /// attaches `criteria` to it, so that the stage's schedule runs on a fixed timestep or only
+    /// while a predicate holds, instead of unconditionally once per app update.
+    ///
+    /// Panics if `criteria` is a [`RunCriteria::FixedTimestep`] with a non-positive or NaN
+    /// `step_seconds`: there's no sensible rate to run the stage at, so this is caught here
+    /// rather than producing a stage that silently never (or always) catches up at run time.
+    pub fn add_stage_with_criteria(
+        &mut self,
+        stage_name: &str,
+        criteria: RunCriteria,
+    ) -> &mut Self {
+        if let RunCriteria::FixedTimestep { step_seconds, .. } = &criteria {
+            assert!(
+                *step_seconds > 0.0,
+                "FixedTimestep step_seconds must be positive, got {} for stage '{}'.",
+                step_seconds,
+                stage_name
+            );
+        }
+
+        if !self.stage_order.iter().any(|stage| stage == stage_name) {
+            self.stage_order.push(stage_name.to_string());
+        }
+
+        self.stage_criteria.insert(stage_name.to_string(), criteria);
+        self
+    }
+
     pub fn add_runnable_to_stage(
         &mut self,
         stage_name: &str,
@@ -148,6 +231,9 @@ impl AppBuilder {
         if let None = self.runnable_stages.get(stage_name) {
             self.runnable_stages
                 .insert(stage_name.to_string(), Vec::new());
+        }
+
+        if !self.stage_order.iter().any(|stage| stage == stage_name) {
             self.stage_order.push(stage_name.to_string());
         }
 
@@ -226,7 +312,7 @@ impl AppBuilder {
     }
 
     #[cfg(feature = "wgpu")]
-    pub fn add_wgpu_renderer(&mut self) -> &mut Self {
+    pub(crate) fn add_wgpu_renderer(&mut self) -> &mut Self {
         self.renderer = Some(Box::new(
             renderer::renderers::wgpu_renderer::WgpuRenderer::new(),
         ));
@@ -234,15 +320,34 @@ impl AppBuilder {
     }
 
     #[cfg(not(feature = "wgpu"))]
-    fn add_wgpu_renderer(&mut self) -> &mut Self {
+    pub(crate) fn add_wgpu_renderer(&mut self) -> &mut Self {
+        self
+    }
+
+    /// Registers a [`Plugin`], giving it a chance to add its own systems, resources, and
+    /// render-graph nodes to the app being built.
+    pub fn add_plugin<T>(&mut self, plugin: T) -> &mut Self
+    where
+        T: Plugin,
+    {
+        plugin.build(self);
         self
     }
 
-    pub fn add_defaults(&mut self) -> &mut Self {
-        self.add_default_resources()
-            .add_default_systems()
-            .add_render_graph_defaults()
-            .add_wgpu_renderer()
+    /// Installs [`DefaultPlugins`](crate::default_plugins::DefaultPlugins), the bundle of
+    /// resources, systems, render graph nodes, and renderer most Bevy apps want. Use
+    /// [`AppBuilder::add_plugin`] directly if you need a different combination.
+    pub fn add_default_plugins(&mut self) -> &mut Self {
+        self.add_plugin(crate::default_plugins::DefaultPlugins)
+    }
+
+    /// Installs only [`AppBuilder::add_default_resources`] and
+    /// [`AppBuilder::add_default_systems`]: no render graph nodes and no renderer. The resulting
+    /// `App` has `renderer: None`, so its run loop paces itself against the wall clock instead of
+    /// a windowing event loop. Use this for automated tests, servers, and other environments that
+    /// want the ECS and asset pipeline without requiring a GPU.
+    pub fn add_headless_defaults(&mut self) -> &mut Self {
+        self.add_default_resources().add_default_systems()
     }
 
     pub fn load_plugin(&mut self, path: &str) -> &mut Self {
@@ -251,3 +356,46 @@ impl AppBuilder {
         self
     }
 }
+
+/// Inserts `stage_name` into `stage_order` at `offset_from_target` positions after `target`
+/// (`0` for immediately before it, `1` for immediately after it). Panics naming `target` if it
+/// isn't present, since there's no stable position to insert relative to.
+fn insert_stage_relative_to(
+    stage_order: &mut Vec<String>,
+    target: &str,
+    stage_name: &str,
+    offset_from_target: usize,
+) {
+    let target_index = stage_order
+        .iter()
+        .position(|stage| stage == target)
+        .unwrap_or_else(|| panic!("Target stage does not exist: {}.", target));
+
+    stage_order.insert(target_index + offset_from_target, stage_name.to_string());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn add_stage_before_inserts_immediately_before_the_target() {
+        let mut stage_order = vec!["first".to_string(), "update".to_string()];
+        insert_stage_relative_to(&mut stage_order, "update", "pre_update", 0);
+        assert_eq!(stage_order, vec!["first", "pre_update", "update"]);
+    }
+
+    #[test]
+    fn add_stage_after_inserts_immediately_after_the_target() {
+        let mut stage_order = vec!["first".to_string(), "update".to_string()];
+        insert_stage_relative_to(&mut stage_order, "update", "post_update", 1);
+        assert_eq!(stage_order, vec!["first", "update", "post_update"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Target stage does not exist: update.")]
+    fn add_stage_before_panics_naming_the_missing_target() {
+        let mut stage_order = vec!["first".to_string()];
+        insert_stage_relative_to(&mut stage_order, "update", "pre_update", 0);
+    }
+}
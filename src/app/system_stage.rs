@@ -0,0 +1,12 @@
+//! Names of the stages that ship in [`AppBuilder::add_default_plugins`](crate::app::AppBuilder::add_default_plugins).
+//! Plugins that need to run relative to one of these should prefer anchoring to them with
+//! [`AppBuilder::add_stage_before`](crate::app::AppBuilder::add_stage_before) or
+//! [`AppBuilder::add_stage_after`](crate::app::AppBuilder::add_stage_after) rather than inventing
+//! a parallel stage name.
+
+pub const FIRST: &str = "first";
+pub const EVENT_UPDATE: &str = "event_update";
+pub const PRE_UPDATE: &str = "pre_update";
+pub const UPDATE: &str = "update";
+pub const POST_UPDATE: &str = "post_update";
+pub const LAST: &str = "last";
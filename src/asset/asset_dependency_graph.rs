@@ -0,0 +1,115 @@
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+/// Tracks parent -> child relationships between handles that were produced from one another at
+/// load time, e.g. a glTF file's `Mesh`, `Texture`, and `StandardMaterial` handles all point back
+/// to the glTF handle they were derived from.
+///
+/// `AssetServer`'s change-detection path consults this graph when a watched file changes on disk,
+/// so that reloading the file invalidates and reloads every handle in the subtree it produced,
+/// not just the top-level asset.
+#[derive(Default)]
+pub struct AssetDependencyGraph<Id>
+where
+    Id: Copy + Eq + Hash,
+{
+    children: HashMap<Id, HashSet<Id>>,
+}
+
+impl<Id> AssetDependencyGraph<Id>
+where
+    Id: Copy + Eq + Hash,
+{
+    pub fn new() -> Self {
+        AssetDependencyGraph {
+            children: HashMap::new(),
+        }
+    }
+
+    /// Records that `child` was produced while loading `parent`, e.g. a glTF's embedded texture
+    /// handle as a child of the glTF handle itself.
+    pub fn add_dependency(&mut self, parent: Id, child: Id) {
+        self.children
+            .entry(parent)
+            .or_insert_with(HashSet::new)
+            .insert(child);
+    }
+
+    /// Returns every handle transitively produced from `root`, including `root` itself. This is
+    /// the full set of handles that must be invalidated and reloaded when `root`'s source file
+    /// changes on disk.
+    pub fn collect_subtree(&self, root: Id) -> HashSet<Id> {
+        let mut subtree = HashSet::new();
+        let mut to_visit = vec![root];
+
+        while let Some(id) = to_visit.pop() {
+            if subtree.insert(id) {
+                if let Some(children) = self.children.get(&id) {
+                    to_visit.extend(children.iter().copied());
+                }
+            }
+        }
+
+        subtree
+    }
+
+    /// Forgets `handle`, both as a parent and as anyone else's child. Called just before a handle
+    /// is reloaded so stale edges from a previous load don't linger.
+    pub fn remove_handle(&mut self, handle: Id) {
+        self.children.remove(&handle);
+        for children in self.children.values_mut() {
+            children.remove(&handle);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn collect_subtree_includes_only_the_root_when_it_has_no_children() {
+        let graph = AssetDependencyGraph::<u32>::new();
+        assert_eq!(graph.collect_subtree(1), [1].iter().copied().collect());
+    }
+
+    #[test]
+    fn collect_subtree_includes_transitive_descendants() {
+        let mut graph = AssetDependencyGraph::new();
+        graph.add_dependency(1, 2);
+        graph.add_dependency(2, 3);
+
+        assert_eq!(
+            graph.collect_subtree(1),
+            [1, 2, 3].iter().copied().collect()
+        );
+    }
+
+    #[test]
+    fn collect_subtree_visits_a_shared_child_once() {
+        // 1 -> 2 -> 4
+        // 1 -> 3 -> 4
+        let mut graph = AssetDependencyGraph::new();
+        graph.add_dependency(1, 2);
+        graph.add_dependency(1, 3);
+        graph.add_dependency(2, 4);
+        graph.add_dependency(3, 4);
+
+        assert_eq!(
+            graph.collect_subtree(1),
+            [1, 2, 3, 4].iter().copied().collect()
+        );
+    }
+
+    #[test]
+    fn remove_handle_drops_it_as_both_a_parent_and_a_child() {
+        let mut graph = AssetDependencyGraph::new();
+        graph.add_dependency(1, 2);
+        graph.add_dependency(2, 3);
+
+        graph.remove_handle(2);
+
+        // 2's own children are forgotten, and 1 no longer lists 2 as a child.
+        assert_eq!(graph.collect_subtree(1), [1].iter().copied().collect());
+    }
+}
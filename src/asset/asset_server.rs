@@ -0,0 +1,188 @@
+use super::AssetDependencyGraph;
+use notify::{DebouncedEvent, RecommendedWatcher, RecursiveMode, Watcher};
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    sync::{
+        mpsc::{channel, Receiver},
+        Arc, Mutex, RwLock,
+    },
+    thread,
+    time::Duration,
+};
+
+pub type HandleId = u64;
+
+#[derive(Debug)]
+pub enum AssetServerError {
+    AssetFolderNotFound,
+    WatcherError(notify::Error),
+}
+
+/// Called for a handle when its source file is reloaded from disk. Typed asset loaders register
+/// one of these for every handle they produce, capturing whatever `AssetStorage<T>` and handle
+/// they need to update, so `AssetServer`'s change-detection path can refresh storages generically
+/// without needing to know the concrete asset type.
+type ReloadFn = Box<dyn Fn(&Path) + Send + Sync>;
+
+#[derive(Default)]
+struct AssetServerInternal {
+    next_handle_id: HandleId,
+    asset_folders: Vec<PathBuf>,
+    path_to_handle: HashMap<PathBuf, HandleId>,
+    handle_to_path: HashMap<HandleId, PathBuf>,
+    dependencies: AssetDependencyGraph<HandleId>,
+    reload_fns: HashMap<HandleId, ReloadFn>,
+}
+
+impl AssetServerInternal {
+    fn get_or_insert_handle(&mut self, path: &Path) -> HandleId {
+        if let Some(handle) = self.path_to_handle.get(path) {
+            return *handle;
+        }
+
+        let handle = self.next_handle_id;
+        self.next_handle_id += 1;
+        self.path_to_handle.insert(path.to_owned(), handle);
+        self.handle_to_path.insert(handle, path.to_owned());
+        handle
+    }
+}
+
+/// Loads assets from disk and, once [`AssetServer::watch_for_changes`] is enabled, keeps them up
+/// to date as their source files change. Composite assets like glTF files load a tree of derived
+/// handles (meshes, textures, materials); [`AssetServer::load_child`] records those parent→child
+/// relationships in an [`AssetDependencyGraph`] so that reloading the parent cascades through the
+/// whole subtree instead of only refreshing the top-level handle.
+///
+/// Cheap to clone: all state lives behind `Arc`, so the background thread spawned by
+/// `watch_for_changes` can hold its own handle to the same server without the caller needing to
+/// wrap it in an `Arc` itself (matching how it's stored and accessed as a `Res<AssetServer>`).
+#[derive(Clone)]
+pub struct AssetServer {
+    internal: Arc<RwLock<AssetServerInternal>>,
+    watcher: Arc<Mutex<Option<RecommendedWatcher>>>,
+}
+
+impl AssetServer {
+    pub fn new() -> Self {
+        AssetServer {
+            internal: Arc::new(RwLock::new(AssetServerInternal::default())),
+            watcher: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    /// Registers the top-level asset at `path` and the folder it lives in (the folders that
+    /// `watch_for_changes` watches), returning the handle that identifies it. The file itself is
+    /// not read here.
+    pub fn load(&self, path: &str) -> Result<HandleId, AssetServerError> {
+        let mut internal = self.internal.write().unwrap();
+        let asset_path = Path::new(path);
+        if let Some(parent) = asset_path.parent() {
+            internal.asset_folders.push(parent.to_owned());
+        }
+
+        Ok(internal.get_or_insert_handle(asset_path))
+    }
+
+    /// Registers `child_path` as an asset produced while loading `parent` (for example a glTF's
+    /// embedded texture), recording the dependency so that reloading `parent` also reloads
+    /// `child_path`. Returns the child's handle. The file itself is not read here.
+    pub fn load_child(&self, parent: HandleId, child_path: &str) -> HandleId {
+        let mut internal = self.internal.write().unwrap();
+        let child = internal.get_or_insert_handle(Path::new(child_path));
+        internal.dependencies.add_dependency(parent, child);
+        child
+    }
+
+    /// Registers the function that refreshes `handle`'s storage when its source file changes.
+    /// Typed asset loaders call this right after loading a handle for the first time.
+    pub fn set_reload_fn(&self, handle: HandleId, reload_fn: impl Fn(&Path) + Send + Sync + 'static) {
+        let mut internal = self.internal.write().unwrap();
+        internal.reload_fns.insert(handle, Box::new(reload_fn));
+    }
+
+    /// Starts watching every folder an asset has been loaded from. When a file changes, every
+    /// handle in its dependency subtree (the file's own handle plus every handle that was loaded
+    /// as a child of it) is reloaded by invoking its registered reload function.
+    pub fn watch_for_changes(&self) -> Result<(), AssetServerError> {
+        let (sender, receiver) = channel();
+        let mut watcher: RecommendedWatcher = Watcher::new(sender, Duration::from_millis(100))
+            .map_err(AssetServerError::WatcherError)?;
+
+        {
+            let internal = self.internal.read().unwrap();
+            if internal.asset_folders.is_empty() {
+                return Err(AssetServerError::AssetFolderNotFound);
+            }
+
+            for folder in internal.asset_folders.iter() {
+                watcher
+                    .watch(folder, RecursiveMode::Recursive)
+                    .map_err(AssetServerError::WatcherError)?;
+            }
+        }
+
+        *self.watcher.lock().unwrap() = Some(watcher);
+        self.spawn_reload_thread(receiver);
+        Ok(())
+    }
+
+    /// Drives the background half of hot-reload: for every `Write`/`Create` event the filesystem
+    /// watcher reports, reload the changed path's full dependency subtree. Runs until the
+    /// watcher (and therefore its channel) is dropped.
+    fn spawn_reload_thread(&self, receiver: Receiver<DebouncedEvent>) {
+        let asset_server = self.clone();
+        thread::spawn(move || {
+            for event in receiver.iter() {
+                match event {
+                    DebouncedEvent::Write(path) | DebouncedEvent::Create(path) => {
+                        asset_server.reload_changed_path(&path);
+                    }
+                    _ => {}
+                }
+            }
+        });
+    }
+
+    /// Reloads every handle in `path`'s dependency subtree. This is the cascading hot-reload
+    /// entry point: it's what makes changing `Monkey.gltf` on disk also re-emit the `Mesh`,
+    /// `Texture`, and `StandardMaterial` handles that were produced from it, not just the
+    /// top-level glTF handle.
+    pub fn reload_changed_path(&self, path: &Path) {
+        let reloads = {
+            let mut internal = self.internal.write().unwrap();
+            let handle = match internal.path_to_handle.get(path) {
+                Some(handle) => *handle,
+                None => return,
+            };
+
+            let subtree = internal.dependencies.collect_subtree(handle);
+            // Drop the stale edges now: the reload below is expected to call `load_child` again
+            // for whatever children the file still produces, so repeated reloads don't keep
+            // accumulating handles for children the file no longer references.
+            internal.dependencies.remove_handle(handle);
+
+            // Take the reload functions out so that no lock is held while they run: a reload
+            // calls back into `load_child` and `set_reload_fn`, which take the write lock.
+            let mut reloads = Vec::new();
+            for child_handle in subtree {
+                if let Some(reload_fn) = internal.reload_fns.remove(&child_handle) {
+                    let child_path = internal.handle_to_path.get(&child_handle).cloned();
+                    reloads.push((child_handle, child_path.unwrap_or_else(|| path.to_owned()), reload_fn));
+                }
+            }
+            reloads
+        };
+
+        for (_, child_path, reload_fn) in reloads.iter() {
+            reload_fn(child_path);
+        }
+
+        // Put each function back unless the reload registered a replacement for its handle.
+        let mut internal = self.internal.write().unwrap();
+        for (child_handle, _, reload_fn) in reloads {
+            internal.reload_fns.entry(child_handle).or_insert(reload_fn);
+        }
+    }
+}
@@ -0,0 +1,11 @@
+mod asset_dependency_graph;
+mod asset_server;
+
+pub use asset_dependency_graph::AssetDependencyGraph;
+pub use asset_server::{AssetServer, AssetServerError, HandleId};
+
+// NOTE: `AssetStorage` and `Handle` live elsewhere in the full tree and aren't part of this
+// snapshot, so they aren't redeclared here. Typed asset loaders are expected to call
+// `AssetServer::set_reload_fn` for every handle they produce, closing over whatever
+// `AssetStorage<T>` and handle they need to refresh, so `AssetServer::reload_changed_path` can
+// update every derived `AssetStorage` generically when a watched file changes.
@@ -0,0 +1,27 @@
+use crate::{
+    app::{system_stage, AppBuilder},
+    plugin::Plugin,
+};
+
+/// The plugin that [`AppBuilder::add_default_plugins`] installs. It registers the stages named in
+/// [`system_stage`] in order and wires up the default resources, systems, render graph nodes, and
+/// renderer that most Bevy apps want. It is a single plugin: its pieces are not separate plugins
+/// and cannot be left out one by one. Outside this crate the resources, systems, and render graph
+/// nodes can be added on their own through their public `AppBuilder` methods; the renderer is
+/// added only by this plugin.
+pub struct DefaultPlugins;
+
+impl Plugin for DefaultPlugins {
+    fn build(&self, app: &mut AppBuilder) {
+        app.add_stage(system_stage::FIRST)
+            .add_stage(system_stage::EVENT_UPDATE)
+            .add_stage(system_stage::PRE_UPDATE)
+            .add_stage(system_stage::UPDATE)
+            .add_stage(system_stage::POST_UPDATE)
+            .add_stage(system_stage::LAST)
+            .add_default_resources()
+            .add_default_systems()
+            .add_render_graph_defaults()
+            .add_wgpu_renderer();
+    }
+}
@@ -0,0 +1,23 @@
+use crate::app::AppBuilder;
+use libloading::{Library, Symbol};
+
+/// Plugins are the primary way of organizing and composing Bevy engine functionality. Each
+/// plugin registers systems, resources, and render-graph nodes onto an [`AppBuilder`] in its
+/// [`Plugin::build`] method, which lets crates opt in to exactly the functionality they need by
+/// calling [`AppBuilder::add_plugin`] instead of calling a long list of bundled setup methods.
+pub trait Plugin: Send + Sync + 'static {
+    fn build(&self, app: &mut AppBuilder);
+}
+
+/// Dynamically loads a [`Plugin`] from the shared library at `path`. The library must export a
+/// `_create_plugin` function returning a `*mut dyn Plugin` obtained from a `Box` (it is taken
+/// back with `Box::from_raw`), typically generated by the
+/// `bevy_plugin!` macro.
+pub fn load_plugin(path: &str) -> (Library, Box<dyn Plugin>) {
+    unsafe {
+        let lib = Library::new(path).unwrap();
+        let func: Symbol<unsafe fn() -> *mut dyn Plugin> = lib.get(b"_create_plugin").unwrap();
+        let plugin = Box::from_raw(func());
+        (lib, plugin)
+    }
+}